@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::http::{Request, Response, StatusCode};
+use tauri::ipc::Channel;
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
 // Types for the API
 #[derive(Debug, Serialize, Deserialize)]
@@ -11,6 +19,7 @@ pub struct AudioFile {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExportOptions {
+    pub audio_path: String,
     pub clip_id: String,
     pub format: String,
     pub template_id: String,
@@ -18,6 +27,27 @@ pub struct ExportOptions {
     pub quality: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Clip {
+    pub id: String,
+    pub start: f64,
+    pub end: f64,
+    pub title: String,
+    pub template_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub audio_path: String,
+    pub clip_count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectFile {
+    audio_path: String,
+    clips: Vec<Clip>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExportResult {
     pub success: bool,
@@ -25,6 +55,24 @@ pub struct ExportResult {
     pub error: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AudioWaveform {
+    pub peaks: Vec<f32>,
+    pub target_points: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportProgress {
+    pub phase: String,
+    pub percent: f64,
+    pub eta_secs: Option<f64>,
+}
+
+// Tracks the ffmpeg child process for each in-flight export so cancel_export
+// can find and kill it by job_id.
+#[derive(Default)]
+struct ExportJobs(Mutex<HashMap<String, CommandChild>>);
+
 // Command to open file dialog and get audio file
 #[tauri::command]
 async fn select_audio_file() -> Result<Option<AudioFile>, String> {
@@ -33,39 +81,561 @@ async fn select_audio_file() -> Result<Option<AudioFile>, String> {
     Ok(None)
 }
 
+// Opens a symphonia probe for the given file, sniffing the format from its extension.
+fn probe_audio(
+    path: &std::path::Path,
+) -> Result<symphonia::core::probe::ProbeResult, String> {
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    symphonia::default::get_probe()
+        .format(
+            &hint,
+            mss,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| e.to_string())
+}
+
+fn probe_duration_secs(path: &std::path::Path) -> Result<f64, String> {
+    let probed = probe_audio(path)?;
+    let track = probed
+        .format
+        .default_track()
+        .ok_or_else(|| "no audio track found".to_string())?;
+
+    let time_base = track
+        .codec_params
+        .time_base
+        .ok_or_else(|| "could not determine duration".to_string())?;
+    let n_frames = track
+        .codec_params
+        .n_frames
+        .ok_or_else(|| "could not determine duration".to_string())?;
+
+    let time = time_base.calc_time(n_frames);
+    Ok(time.seconds as f64 + time.frac)
+}
+
+// Decodes the file and downsamples it into `target_points` buckets, folding each
+// sample into a running min/max per bucket as it's decoded (and dropping it
+// immediately after) rather than buffering the whole multi-hour file in memory.
+fn compute_waveform_peaks(path: &std::path::Path, target_points: usize) -> Result<Vec<f32>, String> {
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::DecoderOptions;
+    use symphonia::core::errors::Error as SymphoniaError;
+
+    if target_points == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut probed = probe_audio(path)?;
+    let track = probed
+        .format
+        .default_track()
+        .ok_or_else(|| "no audio track found".to_string())?;
+    let track_id = track.id;
+    let channels = track
+        .codec_params
+        .channels
+        .map(|c| c.count() as u64)
+        .unwrap_or(1);
+    let n_frames = track
+        .codec_params
+        .n_frames
+        .ok_or_else(|| "could not determine sample count".to_string())?;
+    let total_samples = n_frames * channels;
+    let bucket_size = (total_samples / target_points as u64).max(1);
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| e.to_string())?;
+
+    let mut folder = BucketFolder::new(target_points, bucket_size);
+    loop {
+        let packet = match probed.format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(_)) => break,
+            Err(e) => return Err(e.to_string()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(e.to_string()),
+        };
+        let mut sample_buf = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+        sample_buf.copy_interleaved_ref(decoded);
+        folder.fold(sample_buf.samples().iter().copied());
+    }
+
+    Ok(folder.into_peaks())
+}
+
+// Folds samples into a running min/max per `target_points` bucket, one
+// sample at a time, so only the `target_points` min/max pairs are kept and
+// callers can feed it one decoded packet at a time.
+struct BucketFolder {
+    buckets: Vec<(f32, f32)>,
+    bucket_size: u64,
+    sample_index: u64,
+}
+
+impl BucketFolder {
+    fn new(target_points: usize, bucket_size: u64) -> Self {
+        Self {
+            buckets: vec![(f32::INFINITY, f32::NEG_INFINITY); target_points],
+            bucket_size: bucket_size.max(1),
+            sample_index: 0,
+        }
+    }
+
+    fn fold(&mut self, samples: impl Iterator<Item = f32>) {
+        let target_points = self.buckets.len();
+        for sample in samples {
+            let bucket = ((self.sample_index / self.bucket_size) as usize).min(target_points - 1);
+            let (min, max) = &mut self.buckets[bucket];
+            *min = min.min(sample);
+            *max = max.max(sample);
+            self.sample_index += 1;
+        }
+    }
+
+    // Buckets that saw no samples (e.g. `target_points` exceeds the number of
+    // available samples) stay at their `(INFINITY, NEG_INFINITY)` init value
+    // and are flattened to silence instead of the non-finite sentinel.
+    fn into_peaks(self) -> Vec<f32> {
+        self.buckets
+            .into_iter()
+            .flat_map(|(min, max)| {
+                if min.is_finite() && max.is_finite() {
+                    [min.clamp(-1.0, 1.0), max.clamp(-1.0, 1.0)]
+                } else {
+                    [0.0, 0.0]
+                }
+            })
+            .collect()
+    }
+}
+
+fn hash_key(value: &str) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WaveformCacheEntry {
+    mtime_secs: u64,
+    target_points: usize,
+    peaks: Vec<f32>,
+}
+
 // Command to get audio file info
 #[tauri::command]
 async fn get_audio_info(path: String) -> Result<AudioFile, String> {
-    // Basic implementation - would need actual audio parsing
     let file_path = PathBuf::from(&path);
     let name = file_path
         .file_stem()
         .and_then(|s| s.to_str())
         .unwrap_or("Unknown")
         .to_string();
+    let duration = probe_duration_secs(&file_path)?;
 
     Ok(AudioFile {
         path,
-        duration: 0.0, // Would be calculated from actual audio
+        duration,
         name,
     })
 }
 
-// Command to export a video clip (placeholder for Remotion integration)
+// Command to get downsampled waveform peaks for a file, sized to the frontend's
+// canvas width and cached in the app data dir keyed by path + mtime.
 #[tauri::command]
-async fn export_clip(options: ExportOptions) -> Result<ExportResult, String> {
-    // This would integrate with Remotion CLI for actual video rendering
-    // For now, return a success placeholder
-    Ok(ExportResult {
-        success: true,
-        output_path: Some(format!(
-            "{}/clip_{}_{}.mp4",
-            options.output_dir, options.clip_id, options.format
-        )),
-        error: None,
+async fn get_waveform(
+    app: tauri::AppHandle,
+    path: String,
+    target_points: usize,
+) -> Result<AudioWaveform, String> {
+    let file_path = PathBuf::from(&path);
+    let mtime_secs = tokio::fs::metadata(&file_path)
+        .await
+        .and_then(|meta| meta.modified())
+        .map_err(|e| e.to_string())?
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+
+    let cache_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("waveforms");
+    let cache_file = cache_dir.join(format!("{}.json", hash_key(&path)));
+
+    if let Ok(bytes) = tokio::fs::read(&cache_file).await {
+        if let Ok(entry) = serde_json::from_slice::<WaveformCacheEntry>(&bytes) {
+            if entry.mtime_secs == mtime_secs && entry.target_points == target_points {
+                return Ok(AudioWaveform {
+                    peaks: entry.peaks,
+                    target_points,
+                });
+            }
+        }
+    }
+
+    // The decode loop is CPU-bound and synchronous, so it runs on a blocking
+    // thread instead of tying up a Tokio worker for the length of the file.
+    let peaks = tokio::task::spawn_blocking(move || compute_waveform_peaks(&file_path, target_points))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    tokio::fs::create_dir_all(&cache_dir)
+        .await
+        .map_err(|e| e.to_string())?;
+    let entry = WaveformCacheEntry {
+        mtime_secs,
+        target_points,
+        peaks: peaks.clone(),
+    };
+    if let Ok(serialized) = serde_json::to_vec(&entry) {
+        let _ = tokio::fs::write(&cache_file, serialized).await;
+    }
+
+    Ok(AudioWaveform {
+        peaks,
+        target_points,
     })
 }
 
+// Raw shape of the JSON object `yt-dlp -j` writes to stdout; only the
+// fields we need to populate `AudioFile` are captured here.
+#[derive(Debug, Deserialize)]
+struct YtDlpInfo {
+    title: String,
+    duration: f64,
+    #[serde(default)]
+    filepath: Option<String>,
+    #[serde(default)]
+    requested_downloads: Vec<YtDlpRequestedDownload>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtDlpRequestedDownload {
+    filepath: Option<String>,
+}
+
+// Command to import an episode from a URL by downloading its audio with yt-dlp
+#[tauri::command]
+async fn import_from_url(
+    app: tauri::AppHandle,
+    url: String,
+    output_dir: String,
+) -> Result<AudioFile, String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err("url must be an http(s) URL".to_string());
+    }
+
+    // `-j` prints the metadata JSON to stdout in the same invocation that
+    // downloads the audio (`--no-simulate` keeps `-j` from skipping the
+    // download), so there's only one yt-dlp process to manage.
+    // `--no-playlist` keeps a playlist/channel URL from expanding into one
+    // JSON line (and one download) per entry, which would both blow past
+    // the single `serde_json::from_str` below and download far more than
+    // the caller asked for. `--` stops yt-dlp from ever parsing `url` as a
+    // flag (e.g. `--proxy`) if it somehow still starts with `-` despite the
+    // scheme check above.
+    let output = app
+        .shell()
+        .command("yt-dlp")
+        .args([
+            "-f",
+            "bestaudio",
+            "-x",
+            "-j",
+            "--no-simulate",
+            "--no-playlist",
+            "-o",
+            &format!("{output_dir}/%(id)s.%(ext)s"),
+            "--",
+            &url,
+        ])
+        .output()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let info: YtDlpInfo = serde_json::from_str(stdout.trim())
+        .map_err(|e| format!("failed to parse yt-dlp output: {e}"))?;
+
+    audio_file_from_ytdlp_info(info)
+}
+
+// `requested_downloads[].filepath` reflects the post-extraction file yt-dlp
+// actually wrote (e.g. after `-x` re-encodes to a different container), so it
+// takes priority over the top-level `filepath`, which `-x` doesn't update.
+fn audio_file_from_ytdlp_info(info: YtDlpInfo) -> Result<AudioFile, String> {
+    let path = info
+        .requested_downloads
+        .into_iter()
+        .find_map(|d| d.filepath)
+        .or(info.filepath)
+        .ok_or_else(|| "yt-dlp did not report an output file path".to_string())?;
+
+    Ok(AudioFile {
+        path,
+        duration: info.duration,
+        name: info.title,
+    })
+}
+
+fn project_file_path(app: &tauri::AppHandle, audio_path: &str) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("projects");
+    Ok(dir.join(format!("{}.json", hash_key(audio_path))))
+}
+
+async fn read_project_file(path: &std::path::Path) -> Result<Option<ProjectFile>, String> {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|e| e.to_string()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// Command to persist the clips marked on an episode as a JSON project file
+#[tauri::command]
+async fn save_clips(
+    app: tauri::AppHandle,
+    audio_path: String,
+    clips: Vec<Clip>,
+) -> Result<(), String> {
+    if let Some(bad) = clips.iter().find(|c| c.end <= c.start) {
+        return Err(format!("clip {} has a non-positive duration", bad.id));
+    }
+
+    let path = project_file_path(&app, &audio_path)?;
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    let project = ProjectFile { audio_path, clips };
+    let serialized = serde_json::to_vec_pretty(&project).map_err(|e| e.to_string())?;
+    tokio::fs::write(&path, serialized)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// Command to reload the clips previously saved for an episode
+#[tauri::command]
+async fn load_clips(app: tauri::AppHandle, audio_path: String) -> Result<Vec<Clip>, String> {
+    let path = project_file_path(&app, &audio_path)?;
+    Ok(read_project_file(&path)
+        .await?
+        .map(|project| project.clips)
+        .unwrap_or_default())
+}
+
+// Command to list every saved project, for a "recent episodes" view
+#[tauri::command]
+async fn list_projects(app: tauri::AppHandle) -> Result<Vec<ProjectSummary>, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join("projects");
+
+    let mut entries = match tokio::fs::read_dir(&dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let mut summaries = Vec::new();
+    loop {
+        let entry = match entries.next_entry().await {
+            Ok(Some(entry)) => entry,
+            Ok(None) => break,
+            Err(e) => {
+                eprintln!("list_projects: stopping early on unreadable directory entry: {e}");
+                break;
+            }
+        };
+        match read_project_file(&entry.path()).await {
+            Ok(Some(project)) => summaries.push(ProjectSummary {
+                audio_path: project.audio_path,
+                clip_count: project.clips.len(),
+            }),
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!(
+                    "list_projects: skipping corrupt project file {}: {e}",
+                    entry.path().display()
+                );
+            }
+        }
+    }
+    Ok(summaries)
+}
+
+// Parses an ffmpeg "Duration: 00:02:03.45, start: ..." line.
+fn parse_ffmpeg_duration_secs(line: &str) -> Option<f64> {
+    let after = line.split("Duration:").nth(1)?;
+    let timestamp = after.split(',').next()?.trim();
+    parse_timestamp_secs(timestamp)
+}
+
+// Parses an ffmpeg progress line such as "frame=  120 fps=30 ... time=00:01:02.00 ...".
+fn parse_ffmpeg_time_secs(line: &str) -> Option<f64> {
+    let after = line.split("time=").nth(1)?;
+    let timestamp = after.split_whitespace().next()?;
+    parse_timestamp_secs(timestamp)
+}
+
+fn parse_timestamp_secs(timestamp: &str) -> Option<f64> {
+    let mut parts = timestamp.splitn(3, ':');
+    let hours: f64 = parts.next()?.parse().ok()?;
+    let minutes: f64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    Some(hours * 3600.0 + minutes * 60.0 + seconds)
+}
+
+// Command to export a video clip, streaming render progress over `on_progress`
+// so the UI can show a live bar instead of waiting on a single final result.
+#[tauri::command]
+async fn export_clip(
+    app: tauri::AppHandle,
+    job_id: String,
+    options: ExportOptions,
+    on_progress: Channel<ExportProgress>,
+    jobs: tauri::State<'_, ExportJobs>,
+) -> Result<ExportResult, String> {
+    let project_path = project_file_path(&app, &options.audio_path)?;
+    let clip = read_project_file(&project_path)
+        .await?
+        .and_then(|project| project.clips.into_iter().find(|c| c.id == options.clip_id))
+        .ok_or_else(|| format!("no saved clip with id {}", options.clip_id))?;
+    if clip.end <= clip.start {
+        return Err("clip end must be after clip start".to_string());
+    }
+    let clip_duration_secs = clip.end - clip.start;
+
+    let output_path = format!(
+        "{}/clip_{}_{}.mp4",
+        options.output_dir, options.clip_id, options.format
+    );
+
+    // -ss before -i seeks the input directly instead of decoding-and-discarding
+    // up to clip.start, and gives us an output timeline that starts at 0 so
+    // elapsed `time=` lines below are relative to the clip, not the episode.
+    let (mut rx, child) = app
+        .shell()
+        .command("ffmpeg")
+        .args([
+            "-y",
+            "-ss",
+            &clip.start.to_string(),
+            "-i",
+            &options.audio_path,
+            "-to",
+            &clip_duration_secs.to_string(),
+            "-q",
+            &options.quality,
+            &output_path,
+        ])
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    jobs.0.lock().unwrap().insert(job_id.clone(), child);
+    let started_at = Instant::now();
+
+    let mut exit_success = false;
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stderr(bytes) => {
+                let line = String::from_utf8_lossy(&bytes);
+                if let Some(elapsed) = parse_ffmpeg_time_secs(&line) {
+                    let percent = (elapsed / clip_duration_secs * 100.0).clamp(0.0, 100.0);
+                    let eta_secs = if percent > 0.0 {
+                        Some(started_at.elapsed().as_secs_f64() * (100.0 - percent) / percent)
+                    } else {
+                        None
+                    };
+                    let _ = on_progress.send(ExportProgress {
+                        phase: "rendering".into(),
+                        percent,
+                        eta_secs,
+                    });
+                }
+            }
+            CommandEvent::Terminated(payload) => {
+                exit_success = payload.code == Some(0);
+            }
+            _ => {}
+        }
+    }
+
+    jobs.0.lock().unwrap().remove(&job_id);
+
+    if exit_success {
+        let _ = on_progress.send(ExportProgress {
+            phase: "done".into(),
+            percent: 100.0,
+            eta_secs: Some(0.0),
+        });
+        Ok(ExportResult {
+            success: true,
+            output_path: Some(output_path),
+            error: None,
+        })
+    } else {
+        let error = "render process exited with a non-zero status".to_string();
+        let _ = on_progress.send(ExportProgress {
+            phase: "error".into(),
+            percent: 0.0,
+            eta_secs: None,
+        });
+        Ok(ExportResult {
+            success: false,
+            output_path: None,
+            error: Some(error),
+        })
+    }
+}
+
+// Command to abort an in-flight export started via export_clip
+#[tauri::command]
+fn cancel_export(job_id: String, jobs: tauri::State<'_, ExportJobs>) -> Result<(), String> {
+    if let Some(child) = jobs.0.lock().unwrap().remove(&job_id) {
+        child.kill().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
 // Command to open a URL in the default browser
 #[tauri::command]
 async fn open_url(url: String) -> Result<(), String> {
@@ -81,6 +651,137 @@ fn get_app_data_dir(app: tauri::AppHandle) -> Result<String, String> {
         .map_err(|e| e.to_string())
 }
 
+// The `podaudio://<percent-encoded-path>` scheme lets the webview's <audio>
+// element stream local files directly instead of having the frontend read
+// the whole file into memory to play or scrub it.
+const PODAUDIO_SCHEME: &str = "podaudio";
+
+struct ByteRange {
+    start: u64,
+    end: u64,
+}
+
+// Parses a `Range: bytes=start-end` header value. `end` is clamped to
+// `total_len - 1` when omitted (e.g. `bytes=1024-`).
+fn parse_range_header(value: &str, total_len: u64) -> Option<ByteRange> {
+    let spec = value.strip_prefix("bytes=")?;
+    let (start, end) = spec.split_once('-')?;
+    let start: u64 = start.trim().parse().ok()?;
+    let end: u64 = if end.trim().is_empty() {
+        total_len.saturating_sub(1)
+    } else {
+        end.trim().parse().ok()?
+    };
+    if start > end || end >= total_len {
+        return None;
+    }
+    Some(ByteRange { start, end })
+}
+
+fn content_type_for(path: &std::path::Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "flac" => "audio/flac",
+        "m4a" | "aac" => "audio/aac",
+        _ => "application/octet-stream",
+    }
+}
+
+// Serves a single byte range (or the whole file) for the request's decoded
+// path, reading only what was asked for so multi-hour episodes don't have to
+// be buffered in full before playback can start.
+async fn serve_audio_range(request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let encoded = request.uri().host().unwrap_or_default();
+    let decoded = match percent_encoding::percent_decode_str(encoded).decode_utf8() {
+        Ok(path) => path.into_owned(),
+        Err(_) => return error_response(StatusCode::BAD_REQUEST, "invalid path encoding"),
+    };
+    let path = PathBuf::from(decoded);
+
+    let mut file = match tokio::fs::File::open(&path).await {
+        Ok(file) => file,
+        Err(err) => return error_response(StatusCode::NOT_FOUND, &err.to_string()),
+    };
+    let total_len = match file.metadata().await {
+        Ok(meta) => meta.len(),
+        Err(err) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string()),
+    };
+    let content_type = content_type_for(&path);
+
+    if total_len == 0 {
+        return Response::builder()
+            .status(StatusCode::OK)
+            .header("Content-Type", content_type)
+            .header("Accept-Ranges", "bytes")
+            .header("Content-Length", "0")
+            .body(Vec::new())
+            .unwrap();
+    }
+
+    let range_header = request
+        .headers()
+        .get("range")
+        .and_then(|v| v.to_str().ok());
+
+    let range = match range_header.and_then(|v| parse_range_header(v, total_len)) {
+        Some(range) => range,
+        None if range_header.is_some() => {
+            return Response::builder()
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header("Content-Range", format!("bytes */{total_len}"))
+                .body(Vec::new())
+                .unwrap();
+        }
+        None => ByteRange {
+            start: 0,
+            end: total_len.saturating_sub(1),
+        },
+    };
+
+    let len = range.end - range.start + 1;
+    let mut buf = vec![0u8; len as usize];
+    if let Err(err) = file.seek(std::io::SeekFrom::Start(range.start)).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string());
+    }
+    if let Err(err) = file.read_exact(&mut buf).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &err.to_string());
+    }
+
+    let is_partial = range_header.is_some();
+    let mut builder = Response::builder()
+        .status(if is_partial {
+            StatusCode::PARTIAL_CONTENT
+        } else {
+            StatusCode::OK
+        })
+        .header("Content-Type", content_type)
+        .header("Accept-Ranges", "bytes")
+        .header("Content-Length", len.to_string());
+    if is_partial {
+        builder = builder.header(
+            "Content-Range",
+            format!("bytes {}-{}/{}", range.start, range.end, total_len),
+        );
+    }
+    builder.body(buf).unwrap()
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "text/plain")
+        .body(message.as_bytes().to_vec())
+        .unwrap()
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -88,13 +789,201 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
+        .register_asynchronous_uri_scheme_protocol(PODAUDIO_SCHEME, |_app, request, responder| {
+            tauri::async_runtime::spawn(async move {
+                responder.respond(serve_audio_range(request).await);
+            });
+        })
+        .manage(ExportJobs::default())
         .invoke_handler(tauri::generate_handler![
             select_audio_file,
             get_audio_info,
+            get_waveform,
+            import_from_url,
             export_clip,
+            cancel_export,
+            save_clips,
+            load_clips,
+            list_projects,
             open_url,
             get_app_data_dir
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_header_parses_start_and_end() {
+        let range = parse_range_header("bytes=100-199", 1000).unwrap();
+        assert_eq!(range.start, 100);
+        assert_eq!(range.end, 199);
+    }
+
+    #[test]
+    fn range_header_defaults_missing_end_to_last_byte() {
+        let range = parse_range_header("bytes=900-", 1000).unwrap();
+        assert_eq!(range.start, 900);
+        assert_eq!(range.end, 999);
+    }
+
+    #[test]
+    fn range_header_rejects_start_after_end() {
+        assert!(parse_range_header("bytes=200-100", 1000).is_none());
+    }
+
+    #[test]
+    fn range_header_rejects_end_at_or_past_total_len() {
+        assert!(parse_range_header("bytes=0-1000", 1000).is_none());
+        assert!(parse_range_header("bytes=0-999", 1000).is_some());
+    }
+
+    #[test]
+    fn range_header_rejects_malformed_value() {
+        assert!(parse_range_header("not-a-range", 1000).is_none());
+        assert!(parse_range_header("bytes=abc-def", 1000).is_none());
+    }
+
+    #[test]
+    fn range_header_rejects_zero_length_file() {
+        assert!(parse_range_header("bytes=0-0", 0).is_none());
+    }
+
+    #[test]
+    fn ffmpeg_duration_parses_banner_line() {
+        let line = "  Duration: 00:02:03.45, start: 0.000000, bitrate: 128 kb/s";
+        assert_eq!(parse_ffmpeg_duration_secs(line), Some(123.45));
+    }
+
+    #[test]
+    fn ffmpeg_duration_returns_none_without_banner() {
+        assert_eq!(parse_ffmpeg_duration_secs("frame=  120 fps=30"), None);
+    }
+
+    #[test]
+    fn ffmpeg_time_parses_progress_line() {
+        let line = "frame=  120 fps=30 q=-1.0 size=  256kB time=00:01:02.00 bitrate= 128.0kbits/s";
+        assert_eq!(parse_ffmpeg_time_secs(line), Some(62.0));
+    }
+
+    #[test]
+    fn ffmpeg_time_returns_none_without_marker() {
+        assert_eq!(parse_ffmpeg_time_secs("frame=  120 fps=30"), None);
+    }
+
+    #[test]
+    fn timestamp_rejects_malformed_input() {
+        assert_eq!(parse_timestamp_secs("not-a-timestamp"), None);
+        assert_eq!(parse_timestamp_secs("00:01"), None);
+    }
+
+    #[test]
+    fn hash_key_is_deterministic_and_distinct() {
+        assert_eq!(hash_key("/audio/episode.mp3"), hash_key("/audio/episode.mp3"));
+        assert_ne!(hash_key("/audio/episode.mp3"), hash_key("/audio/other.mp3"));
+    }
+
+    #[test]
+    fn ytdlp_info_prefers_requested_downloads_filepath() {
+        let info = YtDlpInfo {
+            title: "Episode 1".to_string(),
+            duration: 600.0,
+            filepath: Some("/tmp/top-level.webm".to_string()),
+            requested_downloads: vec![YtDlpRequestedDownload {
+                filepath: Some("/tmp/abc123.mp3".to_string()),
+            }],
+        };
+        let audio = audio_file_from_ytdlp_info(info).unwrap();
+        assert_eq!(audio.path, "/tmp/abc123.mp3");
+        assert_eq!(audio.duration, 600.0);
+        assert_eq!(audio.name, "Episode 1");
+    }
+
+    #[test]
+    fn ytdlp_info_falls_back_to_top_level_filepath() {
+        let info = YtDlpInfo {
+            title: "Episode 2".to_string(),
+            duration: 120.0,
+            filepath: Some("/tmp/top-level.webm".to_string()),
+            requested_downloads: vec![],
+        };
+        let audio = audio_file_from_ytdlp_info(info).unwrap();
+        assert_eq!(audio.path, "/tmp/top-level.webm");
+    }
+
+    #[test]
+    fn project_file_round_trips_through_json() {
+        let project = ProjectFile {
+            audio_path: "/audio/episode.mp3".to_string(),
+            clips: vec![
+                Clip {
+                    id: "clip-1".to_string(),
+                    start: 12.5,
+                    end: 45.0,
+                    title: "Intro".to_string(),
+                    template_id: "square".to_string(),
+                },
+                Clip {
+                    id: "clip-2".to_string(),
+                    start: 100.0,
+                    end: 130.25,
+                    title: "Highlight".to_string(),
+                    template_id: "widescreen".to_string(),
+                },
+            ],
+        };
+
+        let serialized = serde_json::to_vec_pretty(&project).unwrap();
+        let deserialized: ProjectFile = serde_json::from_slice(&serialized).unwrap();
+
+        assert_eq!(deserialized.audio_path, project.audio_path);
+        assert_eq!(deserialized.clips.len(), project.clips.len());
+        assert_eq!(deserialized.clips[0].id, "clip-1");
+        assert_eq!(deserialized.clips[0].start, 12.5);
+        assert_eq!(deserialized.clips[1].end, 130.25);
+    }
+
+    #[test]
+    fn bucket_folder_tracks_min_and_max_per_bucket() {
+        let mut folder = BucketFolder::new(2, 2);
+        folder.fold([0.1, -0.2, 0.5, -0.5].into_iter());
+        assert_eq!(folder.into_peaks(), vec![-0.2, 0.1, -0.5, 0.5]);
+    }
+
+    #[test]
+    fn bucket_folder_clamps_out_of_range_samples() {
+        let mut folder = BucketFolder::new(1, 1);
+        folder.fold([-2.0, 2.0].into_iter());
+        assert_eq!(folder.into_peaks(), vec![-1.0, 1.0]);
+    }
+
+    #[test]
+    fn bucket_folder_flattens_empty_buckets_to_zero() {
+        let mut folder = BucketFolder::new(2, 1);
+        folder.fold([0.3].into_iter());
+        assert_eq!(folder.into_peaks(), vec![0.3, 0.3, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn bucket_folder_assigns_overflow_samples_to_the_last_bucket() {
+        // bucket_size=1 with 2 buckets but 3 samples: sample_index 2's natural
+        // bucket (2) is out of range and must clamp into the last bucket.
+        let mut folder = BucketFolder::new(2, 1);
+        folder.fold([0.1, 0.2, 0.9].into_iter());
+        assert_eq!(folder.into_peaks(), vec![0.1, 0.1, 0.2, 0.9]);
+    }
+
+    #[test]
+    fn ytdlp_info_errors_when_no_filepath_is_reported() {
+        let info = YtDlpInfo {
+            title: "Episode 3".to_string(),
+            duration: 60.0,
+            filepath: None,
+            requested_downloads: vec![YtDlpRequestedDownload { filepath: None }],
+        };
+        assert!(audio_file_from_ytdlp_info(info).is_err());
+    }
+}